@@ -0,0 +1,313 @@
+use proc_macro::TokenStream;
+use std::path::Path;
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __bundle_cfg(input: TokenStream) -> TokenStream {
+    bundle_cfg_impl(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn bundle_cfg_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let call: BundleCfgCall = syn::parse2(input)?;
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let dir = Path::new(&manifest_dir).join(call.path.value());
+
+    let entries = std::fs::read_dir(&dir).map_err(|err| {
+        syn::Error::new(
+            call.path.span(),
+            format!("failed to read directory `{}`: {err}", dir.display()),
+        )
+    })?;
+
+    let mut mods = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| syn::Error::new(call.path.span(), err.to_string()))?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        // `file_stem` only strips the final `.rs`, so `foo.plugins.rs` is left
+        // as `foo.plugins` here, letting us split off the inferred feature.
+        let file_stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+
+        let (mod_name, inferred_feature) = match file_stem.split_once('.') {
+            Some((name, feature)) => (name, Some(feature)),
+            None => (file_stem, None),
+        };
+        if matches!(mod_name, "mod" | "lib" | "main") {
+            continue;
+        }
+        let (ident, path_attr) = sanitized_ident(mod_name, &path, call.path.span())?;
+
+        let attr = match inferred_feature {
+            Some(feature) => {
+                let feature = syn::LitStr::new(feature, call.path.span());
+                quote::quote! { #[cfg(feature = #feature)] }
+            }
+            None => {
+                let attr = &call.attr;
+                quote::quote! { #[#attr] }
+            }
+        };
+
+        mods.push(quote::quote! {
+            #path_attr
+            #attr
+            mod #ident;
+        });
+    }
+
+    Ok(quote::quote! { #(#mods)* })
+}
+
+struct BundleCfgCall {
+    path: syn::LitStr,
+    attr: syn::Meta,
+}
+
+impl syn::parse::Parse for BundleCfgCall {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let path: syn::LitStr = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let attr: syn::Meta = input.parse()?;
+        Ok(Self { path, attr })
+    }
+}
+
+/// Declares every `.rs` file in a directory as a module and immediately
+/// re-exports each one's public items into the current scope.
+///
+/// This is the file-based equivalent of following [`bundle!`](../boiler/macro.bundle.html)
+/// with an `expose!` for every module it discovers, producing a ready-made
+/// prelude over a directory of modules without having to name each one by
+/// hand. `mod.rs`, `lib.rs`, and `main.rs` are skipped since they describe
+/// the directory itself rather than a child module.
+///
+/// # Example
+/// ```rust,ignore
+/// flatten!("src/routes");
+/// ```
+///
+/// Given `src/routes/users.rs` and `src/routes/posts.rs`, this expands to
+/// (modules in alphabetical order, each `mod` also carrying a
+/// `#[path = "..."]` attribute pointing at its file):
+/// ```rust,ignore
+/// mod posts;
+/// pub use posts::*;
+/// mod users;
+/// pub use users::*;
+/// ```
+///
+/// # Visibility
+/// An optional leading visibility can be given to scope the generated
+/// re-exports, matching the convention used by `expose!`/`package!`:
+///
+/// ```rust,ignore
+/// flatten!(pub(crate) "src/routes");
+/// ```
+///
+/// # Note
+/// The path must be a string literal relative to the root of your crate.
+#[proc_macro]
+pub fn flatten(input: TokenStream) -> TokenStream {
+    flatten_impl(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn flatten_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let call: FlattenCall = syn::parse2(input)?;
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let dir = Path::new(&manifest_dir).join(call.path.value());
+
+    let entries = std::fs::read_dir(&dir).map_err(|err| {
+        syn::Error::new(
+            call.path.span(),
+            format!("failed to read directory `{}`: {err}", dir.display()),
+        )
+    })?;
+
+    let mut modules = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| syn::Error::new(call.path.span(), err.to_string()))?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        if matches!(stem, "mod" | "lib" | "main") {
+            continue;
+        }
+        modules.push(sanitized_ident(stem, &path, call.path.span())?);
+    }
+    modules.sort_by_key(|(ident, _)| ident.to_string());
+
+    let vis = &call.vis;
+    let mods = modules.into_iter().map(|(ident, path_attr)| {
+        quote::quote! {
+            #path_attr
+            mod #ident;
+            #[allow(unused_imports)]
+            #vis use #ident::*;
+        }
+    });
+    Ok(quote::quote! { #(#mods)* })
+}
+
+struct FlattenCall {
+    vis: syn::Visibility,
+    path: syn::LitStr,
+}
+
+impl syn::parse::Parse for FlattenCall {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let vis: syn::Visibility = input.parse()?;
+        let path: syn::LitStr = input.parse()?;
+        let vis = if matches!(vis, syn::Visibility::Inherited) {
+            syn::parse_quote!(pub)
+        } else {
+            vis
+        };
+        Ok(Self { vis, path })
+    }
+}
+
+/// Recursively declares a directory as a nested module tree.
+///
+/// Unlike `bundle!`/`automod::dir!`, which only looks at the top level of a
+/// directory, `bundle_tree!` walks subdirectories too: each subdirectory
+/// becomes an inline `pub mod <dirname> { ... }` containing `mod <file>;`
+/// declarations for its files and a recursive inline module for each of its
+/// own subdirectories, exactly mirroring the directory layout.
+///
+/// # Example
+/// ```rust,ignore
+/// bundle_tree!("src/routes");
+/// ```
+///
+/// Given `src/routes/users.rs` and `src/routes/admin/settings.rs`, this
+/// expands to:
+/// ```rust,ignore
+/// mod users;
+/// pub mod admin {
+///     mod settings;
+/// }
+/// ```
+///
+/// # Note
+/// - The path must be a string literal relative to the root of your crate.
+/// - `mod.rs`, `lib.rs`, and `main.rs` are skipped, as are non-`.rs` files.
+/// - Empty directories produce an empty (but still valid) inline module.
+/// - Directory and file names are sanitized into valid identifiers (hyphens
+///   become underscores), and every generated `mod` carries an explicit
+///   `#[path = "..."]` attribute pointing at the file or directory it came
+///   from; a name that still can't be made a valid identifier is a compile
+///   error naming the offender.
+#[proc_macro]
+pub fn bundle_tree(input: TokenStream) -> TokenStream {
+    bundle_tree_impl(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn bundle_tree_impl(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let path: syn::LitStr = syn::parse2(input)?;
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let dir = Path::new(&manifest_dir).join(path.value());
+    bundle_tree_dir(&dir, path.span())
+}
+
+fn bundle_tree_dir(dir: &Path, span: proc_macro2::Span) -> syn::Result<proc_macro2::TokenStream> {
+    let entries = std::fs::read_dir(dir).map_err(|err| {
+        syn::Error::new(
+            span,
+            format!("failed to read directory `{}`: {err}", dir.display()),
+        )
+    })?;
+
+    let mut files = Vec::new();
+    let mut subdirs = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|err| syn::Error::new(span, err.to_string()))?;
+        let path = entry.path();
+        if path.is_dir() {
+            subdirs.push(path);
+            continue;
+        }
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        if matches!(stem, "mod" | "lib" | "main") {
+            continue;
+        }
+        files.push(sanitized_ident(stem, &path, span)?);
+    }
+    files.sort_by_key(|(ident, _)| ident.to_string());
+    subdirs.sort();
+
+    let file_mods = files.into_iter().map(|(ident, path_attr)| {
+        quote::quote! {
+            #path_attr
+            mod #ident;
+        }
+    });
+
+    let mut nested = Vec::new();
+    for subdir in subdirs {
+        let name = subdir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let (ident, path_attr) = sanitized_ident(name, &subdir, span)?;
+        let body = bundle_tree_dir(&subdir, span)?;
+        nested.push(quote::quote! {
+            #path_attr
+            pub mod #ident {
+                #body
+            }
+        });
+    }
+
+    Ok(quote::quote! {
+        #( #file_mods )*
+        #( #nested )*
+    })
+}
+
+/// Sanitizes a file stem or directory name (hyphens to underscores) into a
+/// valid module identifier, erroring clearly if it still isn't one. Returns
+/// the identifier alongside a `#[path = "..."]` attribute pointing at
+/// `disk_path`.
+///
+/// The attribute is always emitted, not only when sanitization changes the
+/// name: the implicit directory Rust would otherwise use for the generated
+/// `mod` item is derived from the *invoking* file, which has no relation to
+/// the directory `bundle_tree!`/`bundle!` were actually told to read, so it
+/// can't be relied on to find the right file or directory on its own.
+fn sanitized_ident(
+    stem: &str,
+    disk_path: &Path,
+    span: proc_macro2::Span,
+) -> syn::Result<(syn::Ident, proc_macro2::TokenStream)> {
+    let sanitized = stem.replace('-', "_");
+    let ident = syn::parse_str::<syn::Ident>(&sanitized).map_err(|_| {
+        syn::Error::new(
+            span,
+            format!("name `{stem}` can't be made into a valid module identifier"),
+        )
+    })?;
+    let path = syn::LitStr::new(&disk_path.display().to_string(), span);
+    Ok((ident, quote::quote! { #[path = #path] }))
+}
@@ -9,18 +9,125 @@
 /// bundle!("src/routes");
 /// ```
 ///
+/// # Attribute forwarding
+/// Passing an attribute as a second argument applies it to every generated
+/// `mod` declaration, letting a whole folder of modules be gated behind a
+/// Cargo feature or similar:
+///
+/// ```rust,ignore
+/// bundle!("src/plugins", cfg(feature = "plugins"));
+/// ```
+///
+/// A file named `<name>.<feature>.rs` overrides the given attribute for
+/// just that file: everything before the first `.` becomes the module name
+/// and everything after it becomes the inferred feature, so the file is
+/// declared as `mod <name>;` with `#[cfg(feature = "<feature>")]` instead
+/// of the attribute passed to `bundle!`. This form reads the directory
+/// itself, so it does not go through [`automod`].
+///
 /// # Requirements
-/// - Requires the [`automod`](https://docs.rs/automod) crate to be added as a dependency.
+/// - The plain, single-argument form requires the
+///   [`automod`](https://docs.rs/automod) crate to be added as a dependency.
 ///
 /// # Note
-/// The path must be a string literal relative to the root of your crate.
+/// - The path must be a string literal relative to the root of your crate.
+/// - In the attribute-forwarding form, a hyphenated module name is sanitized
+///   to underscores and given a `#[path = "..."]` attribute pointing back at
+///   the original file, the same way [`bundle_tree!`] handles directory and
+///   file names.
 #[macro_export]
 macro_rules! bundle {
     ($path:expr) => {
         automod::dir!($path);
     };
+    ($path:expr, $attr:meta) => {
+        $crate::__bundle_cfg!($path, $attr);
+    };
 }
 
+/// Implementation detail of [`bundle!`]'s attribute-forwarding form. Not
+/// part of the public API.
+#[doc(hidden)]
+pub use boiler_macros::__bundle_cfg;
+
+/// Declares every `.rs` file in a directory as a module and immediately
+/// re-exports each one's public items into the current scope.
+///
+/// This is the file-based equivalent of following [`bundle!`] with an
+/// [`expose!`] for every module it discovers, producing a ready-made
+/// prelude over a directory of modules without having to name each one by
+/// hand. `mod.rs`, `lib.rs`, and `main.rs` are skipped since they describe
+/// the directory itself rather than a child module.
+///
+/// # Example
+/// ```rust,ignore
+/// flatten!("src/routes");
+/// ```
+///
+/// Given `src/routes/users.rs` and `src/routes/posts.rs`, this expands to
+/// (modules in alphabetical order, each `mod` also carrying a
+/// `#[path = "..."]` attribute pointing at its file):
+/// ```rust,ignore
+/// mod posts;
+/// pub use posts::*;
+/// mod users;
+/// pub use users::*;
+/// ```
+///
+/// # Visibility
+/// An optional leading visibility can be given to scope the generated
+/// re-exports, matching the convention used by [`expose!`] and [`package!`]:
+///
+/// ```rust,ignore
+/// flatten!(pub(crate) "src/routes");
+/// ```
+///
+/// # Requirements
+/// Unlike [`bundle!`], this does not build on the [`automod`] crate: the
+/// re-exports need to know each discovered file's module name at macro
+/// expansion time, so `flatten!` is its own procedural macro (implemented in
+/// the `boiler-macros` companion crate) that reads the directory at compile
+/// time.
+///
+/// # Note
+/// The path must be a string literal relative to the root of your crate.
+pub use boiler_macros::flatten;
+
+/// Recursively declares a directory as a nested module tree.
+///
+/// Unlike [`bundle!`] / `automod::dir!`, which only looks at the top level of
+/// a directory, `bundle_tree!` walks subdirectories too: each subdirectory
+/// becomes an inline `pub mod <dirname> { ... }` containing `mod <file>;`
+/// declarations for its files and a recursive inline module for each of its
+/// own subdirectories, exactly mirroring the directory layout.
+///
+/// # Example
+/// ```rust,ignore
+/// bundle_tree!("src/routes");
+/// ```
+///
+/// Given `src/routes/users.rs` and `src/routes/admin/settings.rs`, this
+/// expands to:
+/// ```rust,ignore
+/// mod users;
+/// pub mod admin {
+///     mod settings;
+/// }
+/// ```
+///
+/// # Note
+/// - The path must be a string literal relative to the root of your crate.
+/// - `mod.rs`, `lib.rs`, and `main.rs` are skipped, as are non-`.rs` files.
+/// - Empty directories produce an empty (but still valid) inline module.
+/// - Directory and file names are sanitized into valid identifiers (hyphens
+///   become underscores), and every generated `mod` carries an explicit
+///   `#[path = "..."]` attribute pointing at the file or directory it came
+///   from; a name that still can't be made a valid identifier is a compile
+///   error naming the offender.
+/// - Implemented in the `boiler-macros` companion crate, since reading a
+///   directory tree at compile time needs a procedural macro.
+pub use boiler_macros::bundle_tree;
+
 /// Re-exports the provided modules directly into the current scope.
 ///
 /// This macro assumes that the modules are available **in the same scope**
@@ -28,23 +135,101 @@ macro_rules! bundle {
 /// flatten modules and avoid nested access like `mod::Type`.
 ///
 /// # Example
-/// ```rust
+/// ```rust,ignore
 /// expose!(models, utils);
 /// ```
 ///
 /// This expands to:
-/// ```rust
+/// ```rust,ignore
 /// pub use models::*;
 /// pub use utils::*;
 /// ```
+///
+/// # Visibility
+/// An optional leading visibility (`pub`, `pub(crate)`, `pub(super)`, ...) can
+/// be given to scope the generated `use` statements instead of defaulting to
+/// `pub`. Multiple visibility groups can be combined in a single invocation
+/// by separating them with `;`:
+///
+/// ```rust,ignore
+/// expose!(pub(crate) models, utils; pub config;);
+/// ```
+///
+/// This expands to:
+/// ```rust,ignore
+/// pub(crate) use models::*;
+/// pub(crate) use utils::*;
+/// pub use config::*;
+/// ```
+///
+/// # Selective and renaming re-exports
+/// Instead of a bare module name, an entry can narrow the re-export to a
+/// brace list of items (forwarded verbatim, so renames via `as` work too),
+/// a single `module::item`, a single `module::item as alias`, or an
+/// explicit `module::*`:
+///
+/// ```rust,ignore
+/// expose!(models::{User, Post as BlogPost}, models::Comment, utils::*);
+/// ```
+///
+/// This expands to:
+/// ```rust,ignore
+/// pub use models::{User, Post as BlogPost};
+/// pub use models::Comment;
+/// pub use utils::*;
+/// ```
 #[macro_export]
 macro_rules! expose {
     ( $( $module:ident ),* $(,)? ) => {
-        $( 
+        $(
             #[allow(unused_imports)]
             pub use $module::*;
         )*
     };
+    ( $( $vis:vis $( $module:ident ),+ $(,)? );+ $(;)? ) => {
+        $(
+            $(
+                #[allow(unused_imports)]
+                $vis use $module::*;
+            )+
+        )+
+    };
+    ( $($selector:tt)* ) => {
+        $crate::__expose_selective!( $($selector)* );
+    };
+}
+
+/// Implementation detail of [`expose!`]'s selective/renaming form. Not part
+/// of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expose_selective {
+    () => {};
+    ( $module:ident :: { $($items:tt)* } $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use $module::{ $($items)* };
+        $crate::__expose_selective!( $($($rest)*)? );
+    };
+    ( $module:ident :: $item:ident as $alias:ident $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use $module::$item as $alias;
+        $crate::__expose_selective!( $($($rest)*)? );
+    };
+    ( $module:ident :: $item:ident $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use $module::$item;
+        $crate::__expose_selective!( $($($rest)*)? );
+    };
+    ( $module:ident :: * $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use $module::*;
+        $crate::__expose_selective!( $($($rest)*)? );
+    };
+    ( $module:ident $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use $module::*;
+        $crate::__expose_selective!( $($($rest)*)? );
+    };
 }
 
 /// Re-exports modules from the **parent module** into the current scope.
@@ -55,7 +240,7 @@ macro_rules! expose {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// // src/app/mod.rs
 /// pub mod models;
 /// pub mod services;
@@ -66,7 +251,7 @@ macro_rules! expose {
 /// ```
 ///
 /// This expands to:
-/// ```rust
+/// ```rust,ignore
 /// pub use super::models::*;
 /// pub use super::services::*;
 /// ```
@@ -74,23 +259,100 @@ macro_rules! expose {
 /// # Result
 /// Any module that imports `app::prelude::*` will now have access to everything
 /// from `models` and `services` as if it were defined in `prelude`.
+///
+/// # Visibility
+/// Like [`expose!`], an optional leading visibility can be given to scope the
+/// generated re-exports, and multiple visibility groups can be combined in a
+/// single invocation by separating them with `;`:
+///
+/// ```rust,ignore
+/// // src/app/prelude.rs
+/// package!(pub(crate) models; pub services;);
+/// ```
+///
+/// This expands to:
+/// ```rust,ignore
+/// pub(crate) use super::models::*;
+/// pub use super::services::*;
+/// ```
+///
+/// # Selective and renaming re-exports
+/// Like [`expose!`], an entry can narrow the re-export to a brace list of
+/// items, a single `module::item`, a single `module::item as alias`, or an
+/// explicit `module::*`, all resolved relative to `super::`:
+///
+/// ```rust,ignore
+/// // src/app/prelude.rs
+/// package!(models::{User, Post as BlogPost}, models::Comment, services::*);
+/// ```
+///
+/// This expands to:
+/// ```rust,ignore
+/// pub use super::models::{User, Post as BlogPost};
+/// pub use super::models::Comment;
+/// pub use super::services::*;
+/// ```
 #[macro_export]
 macro_rules! package {
     ( $( $module:ident ),* $(,)? ) => {
-        $( 
+        $(
             #[allow(unused_imports)]
             pub use super::$module::*;
         )*
     };
+    ( $( $vis:vis $( $module:ident ),+ $(,)? );+ $(;)? ) => {
+        $(
+            $(
+                #[allow(unused_imports)]
+                $vis use super::$module::*;
+            )+
+        )+
+    };
+    ( $($selector:tt)* ) => {
+        $crate::__package_selective!( $($selector)* );
+    };
+}
+
+/// Implementation detail of [`package!`]'s selective/renaming form. Not part
+/// of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __package_selective {
+    () => {};
+    ( $module:ident :: { $($items:tt)* } $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use super::$module::{ $($items)* };
+        $crate::__package_selective!( $($($rest)*)? );
+    };
+    ( $module:ident :: $item:ident as $alias:ident $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use super::$module::$item as $alias;
+        $crate::__package_selective!( $($($rest)*)? );
+    };
+    ( $module:ident :: $item:ident $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use super::$module::$item;
+        $crate::__package_selective!( $($($rest)*)? );
+    };
+    ( $module:ident :: * $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use super::$module::*;
+        $crate::__package_selective!( $($($rest)*)? );
+    };
+    ( $module:ident $(, $($rest:tt)*)? ) => {
+        #[allow(unused_imports)]
+        pub use super::$module::*;
+        $crate::__package_selective!( $($($rest)*)? );
+    };
 }
 
 /// Marks a module as an extension of its parent by importing all parent items.
-/// 
+///
 /// This macro expands to `use super::*;`, bringing all public items from the parent
 /// module into the current scope. It is functionally equivalent to writing that line
 /// manually, but serves as a **semantic indicator** that the current module is meant
 /// to build upon or extend its parent.
-/// 
+///
 /// # Purpose
 /// Use `extend!` when you want to clearly signal intent â€” that this module
 /// relies on or enhances the parent â€” rather than just importing for utility.
@@ -102,6 +364,6 @@ macro_rules! package {
 #[macro_export]
 macro_rules! extend {
     () => {
-        use super::*;   
+        use super::*;
     };
-}
\ No newline at end of file
+}
@@ -0,0 +1,14 @@
+//! Round-trip expansion test for `bundle!`'s attribute-forwarding form.
+
+boiler::bundle!("tests/bundle_cfg", cfg(test));
+
+#[test]
+fn forwards_the_attribute_to_every_module() {
+    let _ = widgets::Widgets;
+}
+
+#[cfg(feature = "demo")]
+#[test]
+fn infers_a_feature_from_the_file_name() {
+    let _ = extra::Extra;
+}
@@ -0,0 +1,8 @@
+//! Round-trip expansion test for `bundle_tree!`.
+//!
+//! `bundle_tree!` only mirrors the directory structure; like a plain `mod
+//! foo;`, each file module is declared private (only the inline modules for
+//! subdirectories are `pub`), so the fixtures assert their own reachability
+//! rather than being poked at from outside.
+
+boiler::bundle_tree!("tests/bundle_tree");
@@ -0,0 +1,6 @@
+pub struct ModA;
+
+#[test]
+fn mod_a_is_reachable() {
+    let _ = ModA;
+}
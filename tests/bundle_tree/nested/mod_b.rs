@@ -0,0 +1,6 @@
+pub struct ModB;
+
+#[test]
+fn mod_b_is_reachable_from_its_own_nested_module() {
+    let _ = ModB;
+}
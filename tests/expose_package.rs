@@ -0,0 +1,75 @@
+//! Round-trip expansion tests for `expose!`, `package!`, and `extend!`.
+
+mod vis_groups {
+    mod models {
+        pub struct User;
+    }
+    mod utils {
+        pub struct Logger;
+    }
+    mod config {
+        pub struct Settings;
+    }
+
+    boiler::expose!(pub(crate) models, utils; pub config;);
+
+    #[test]
+    fn reexports_with_requested_visibility() {
+        let _ = User;
+        let _ = Logger;
+        let _ = Settings;
+    }
+}
+
+mod selective {
+    mod models {
+        pub struct User;
+        pub struct Post;
+        pub struct Comment;
+    }
+    mod utils {
+        pub struct Logger;
+    }
+
+    boiler::expose!(models::{User, Post as BlogPost}, models::Comment, utils::*);
+
+    #[test]
+    fn selective_and_renamed_items_are_reexported() {
+        let _ = User;
+        let _ = BlogPost;
+        let _ = Comment;
+        let _ = Logger;
+    }
+}
+
+mod package_tests {
+    pub mod models {
+        pub struct User;
+    }
+    pub mod services {
+        pub struct Mailer;
+    }
+
+    mod prelude {
+        boiler::package!(models::User, services::*);
+
+        #[test]
+        fn reexports_from_parent_module() {
+            let _ = User;
+            let _ = Mailer;
+        }
+    }
+}
+
+mod extend_tests {
+    pub struct Widget;
+
+    mod child {
+        boiler::extend!();
+
+        #[test]
+        fn brings_parent_items_into_scope() {
+            let _ = Widget;
+        }
+    }
+}
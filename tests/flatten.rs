@@ -0,0 +1,10 @@
+//! Round-trip expansion test for `flatten!`.
+
+boiler::flatten!("tests/flatten");
+
+#[test]
+fn flattens_every_file_in_the_directory() {
+    let _ = Alpha;
+    let _ = Beta;
+    let _ = MultiWord;
+}